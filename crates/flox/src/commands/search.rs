@@ -1,4 +1,7 @@
 use std::collections::{HashMap, HashSet};
+use std::collections::hash_map::DefaultHasher;
+use std::fs;
+use std::hash::{Hash, Hasher};
 use std::io::{BufWriter, Write};
 
 use anyhow::{bail, Context, Result};
@@ -16,6 +19,7 @@ use flox_rust_sdk::models::search::{
     Subtree,
 };
 use log::debug;
+use runix::command_line::flag::NixConfigSettings;
 
 use crate::commands::{ConcreteEnvironment, EnvironmentSelect};
 use crate::config::features::{Features, SearchStrategy};
@@ -24,6 +28,9 @@ use crate::utils::toml_to_json;
 
 const SEARCH_INPUT_SEPARATOR: &'_ str = ":";
 const DEFAULT_DESCRIPTION: &'_ str = "<no description provided>";
+/// Maximum number of "did you mean ...?" suggestions to print when a search
+/// or show query comes back empty.
+const MAX_SUGGESTIONS: usize = 3;
 
 #[derive(Bpaf, Clone)]
 pub struct ChannelArgs {}
@@ -39,23 +46,43 @@ pub struct Search {
     #[bpaf(long)]
     pub refresh: bool,
 
+    /// never touch the network; only reuse a cached result for an
+    /// identical query, and error out if none exists instead of invoking
+    /// `pkgdb`. Conflicts with `--refresh`.
+    #[bpaf(long)]
+    pub offline: bool,
+
+    /// restrict results to a particular Nix system, e.g. `x86_64-linux`.
+    /// May be given multiple times; defaults to the host system.
+    #[bpaf(long("system"), argument("SYSTEM"))]
+    pub systems: Vec<String>,
+
+    /// search across all supported systems instead of just the host system,
+    /// and print the systems each package is available on
+    #[bpaf(long)]
+    pub all_systems: bool,
+
+    /// treat a bare version after `@` as an exact pin instead of the
+    /// default `^` (caret) range, e.g. `node@18.1.0` matches only `18.1.0`
+    /// rather than `>=18.1.0 <19.0.0`
+    #[bpaf(long)]
+    pub exact_version: bool,
+
     /// query string of the form `<REGEX>[@<SEMVER-RANGE>]` used to filter
     /// match against package names/descriptions, and semantic version.
     /// Regex pattern is `PCRE` style, and semver ranges use the
-    /// `node-semver` syntax.
+    /// `node-semver` syntax. A bare version (e.g. `node@18`) is treated as
+    /// `^18` unless `--exact-version` is given; explicit `=`, `~`, `^`, and
+    /// comparator ranges are always honored as written.
     /// Exs: `(hello|coreutils)`, `node@>=16`, `coreutils@9.1`
+    /// If both the regex and the range fail to parse, the regex error is
+    /// reported first, since it's validated before the range is.
     #[bpaf(positional("search-term"))]
     pub search_term: String,
 }
 
 // Your first run will be slow, it's creating databases, but after that -
 //   it's fast!
-//
-// `NIX_CONFIG='allow-import-from-derivation = true'` may be required because
-// `pkgdb` disables this by default, but some flakes require it.
-// Ideally this setting should be controlled by Registry preferences,
-// which is TODO.
-// Luckily most flakes don't.
 impl Search {
     pub async fn handle(self, flox: Flox) -> Result<()> {
         subcommand_metric!("search");
@@ -97,15 +124,58 @@ impl Search {
                 },
             };
 
+        if self.offline && self.refresh {
+            bail!("--offline and --refresh cannot be used together");
+        }
+
+        let global_manifest = global_manifest_path(&flox).try_into()?;
+        let normalized_term = normalize_semver_range(&self.search_term, self.exact_version);
+        let cache_key = search_cache_key(
+            &normalized_term,
+            &manifest,
+            &global_manifest,
+            lockfile.as_ref(),
+            &self.systems,
+            self.all_systems,
+            self.exact_version,
+            Features::parse()?.search_strategy,
+        )?;
+
         let search_params = construct_search_params(
             &self.search_term,
             manifest,
-            global_manifest_path(&flox).try_into()?,
+            global_manifest,
             lockfile,
+            &self.systems,
+            self.all_systems,
+            self.exact_version,
         )?;
 
-        let (results, exit_status) = do_search(&search_params)?;
-        debug!("search call exit status: {}", exit_status.to_string());
+        // Same resolution inputs as a prior call means the same results, so
+        // reuse them instead of invoking `pkgdb` again. `--refresh` always
+        // bypasses the cache since its entire point is to pick up changes
+        // that a cache hit would paper over.
+        let cached = (!self.refresh)
+            .then(|| read_cached_search_results(&flox, &cache_key))
+            .flatten();
+        let (results, exit_status) = match cached {
+            Some(results) => {
+                debug!("using cached search results for key {cache_key}");
+                (results, None)
+            },
+            None if self.offline => {
+                bail!(
+                    "--offline was given but no cached result exists for this query; run the \
+                     search once without --offline to populate the cache"
+                );
+            },
+            None => {
+                let (results, exit_status) = do_search(&search_params)?;
+                debug!("search call exit status: {}", exit_status.to_string());
+                write_cached_search_results(&flox, &cache_key, &results)?;
+                (results, Some(exit_status))
+            },
+        };
 
         // Render what we have no matter what, then indicate whether we encountered an error.
         // FIXME: We may have warnings on `stderr` even with a successful call to `pkgdb`.
@@ -116,39 +186,192 @@ impl Search {
             render_search_results_json(results)?;
         } else {
             debug!("printing search results as user facing");
-            render_search_results_user_facing(&self.search_term, results)?;
+            render_search_results_user_facing(
+                &self.search_term,
+                results,
+                &search_params,
+                self.all_systems,
+            )?;
         }
-        if !exit_status.success() {
-            bail!(
-                "pkgdb exited with status code: {}",
-                exit_status.code().unwrap_or(-1),
-            );
+        if let Some(exit_status) = exit_status {
+            if !exit_status.success() {
+                bail!(
+                    "pkgdb exited with status code: {}",
+                    exit_status.code().unwrap_or(-1),
+                );
+            }
         };
 
         Ok(())
     }
 }
 
+/// The per-call Nix settings `pkgdb` is invoked with, in place of forcing
+/// `NIX_CONFIG='allow-import-from-derivation = true'` on the whole process:
+/// `pkgdb` disables IFD by default, but some flakes require it.
+fn default_nix_config() -> NixConfigSettings {
+    NixConfigSettings {
+        allow_import_from_derivation: Some(true),
+        ..Default::default()
+    }
+}
+
 fn construct_search_params(
     search_term: &str,
     manifest: PathOrJson,
     global_manifest: PathOrJson,
     lockfile: Option<PathOrJson>,
+    systems: &[String],
+    all_systems: bool,
+    exact_version: bool,
 ) -> Result<SearchParams> {
-    let query = Query::from_str(
-        search_term,
+    let mut query = Query::from_str(
+        &normalize_semver_range(search_term, exact_version),
         Features::parse()?.search_strategy == SearchStrategy::MatchName,
     )?;
+    query.systems = systems_for_query(systems, all_systems)?;
     let params = SearchParams {
         manifest,
         global_manifest,
         lockfile,
         query,
+        nix_config: default_nix_config(),
     };
     debug!("search params raw: {:?}", params);
     Ok(params)
 }
 
+/// Resolve the `--system`/`--all-systems` flags into the value `Query`
+/// expects: `None` keeps `Query::from_str`'s implicit host-system default,
+/// `Some(vec![])` means "every system", and `Some(systems)` restricts the
+/// query to exactly the given systems.
+fn systems_for_query(systems: &[String], all_systems: bool) -> Result<Option<Vec<String>>> {
+    if all_systems && !systems.is_empty() {
+        bail!("--system and --all-systems cannot be used together");
+    }
+    Ok(if all_systems {
+        Some(Vec::new())
+    } else if !systems.is_empty() {
+        Some(systems.to_vec())
+    } else {
+        None
+    })
+}
+
+/// Rewrite a bare version after `@` (e.g. `node@18`) to an explicit caret
+/// range (`node@^18`), matching the node-semver convention that a bare
+/// version implies `^`. Explicit `=`, `~`, `^`, and comparator ranges
+/// (anything not starting with a digit) are left untouched, as is the
+/// term when there's no `@<RANGE>` part at all or `--exact-version` was
+/// given. If the regex part contains its own `@` (unlikely but not
+/// forbidden), only the final `@` is treated as the version separator.
+///
+/// This only rewrites the range's syntax; it doesn't validate either half
+/// itself. Both are validated together downstream by `Query::from_str`,
+/// which checks the regex before the range, so a term with both an invalid
+/// regex and an invalid range surfaces the regex error first.
+fn normalize_semver_range(search_term: &str, exact_version: bool) -> String {
+    if exact_version {
+        return search_term.to_string();
+    }
+    let Some((term, range)) = search_term.rsplit_once('@') else {
+        return search_term.to_string();
+    };
+    let is_bare_version = range.starts_with(|c: char| c.is_ascii_digit());
+    if is_bare_version {
+        format!("{term}@^{range}")
+    } else {
+        search_term.to_string()
+    }
+}
+
+/// The file a search/show result cache is persisted to, relative to
+/// [Flox::cache_dir].
+const SEARCH_CACHE_FILE_NAME: &'_ str = "search-cache.json";
+
+/// Compute a content-addressed cache key for a search/show query: hashes of
+/// every input that can change which results `pkgdb` returns for it (the
+/// normalized search term, manifest, global manifest, lockfile, search
+/// strategy, and the system filtering options). Adopted from butido's "find
+/// by identical inputs" idea, so repeated identical queries (e.g. from CI)
+/// can skip `do_search` entirely.
+///
+/// `normalized_term` must already have gone through [normalize_semver_range]
+/// (and, for `flox show`, had its input prefix stripped) so that two
+/// queries that resolve to the same [Query] always hash identically,
+/// regardless of how their raw, pre-normalization text happened to differ.
+#[allow(clippy::too_many_arguments)]
+fn search_cache_key(
+    normalized_term: &str,
+    manifest: &PathOrJson,
+    global_manifest: &PathOrJson,
+    lockfile: Option<&PathOrJson>,
+    systems: &[String],
+    all_systems: bool,
+    exact_version: bool,
+    search_strategy: SearchStrategy,
+) -> Result<String> {
+    let mut hasher = DefaultHasher::new();
+    normalized_term.hash(&mut hasher);
+    hash_path_or_json(manifest)?.hash(&mut hasher);
+    hash_path_or_json(global_manifest)?.hash(&mut hasher);
+    lockfile.map(hash_path_or_json).transpose()?.hash(&mut hasher);
+    systems.hash(&mut hasher);
+    all_systems.hash(&mut hasher);
+    exact_version.hash(&mut hasher);
+    format!("{search_strategy:?}").hash(&mut hasher);
+    Ok(format!("{:016x}", hasher.finish()))
+}
+
+/// Hash the content a [PathOrJson] resolves to: the file contents for a
+/// path, or the serialized JSON for an inline value.
+fn hash_path_or_json(p: &PathOrJson) -> Result<u64> {
+    let mut hasher = DefaultHasher::new();
+    match p {
+        PathOrJson::Path(path) => {
+            fs::read(path)
+                .with_context(|| format!("couldn't read {path:?} to compute search cache key"))?
+                .hash(&mut hasher);
+        },
+        PathOrJson::Json(json) => {
+            serde_json::to_string(json)?.hash(&mut hasher);
+        },
+    }
+    Ok(hasher.finish())
+}
+
+/// Look up a previously cached [SearchResults] for `cache_key`. Returns
+/// `None` on any cache miss or error (missing/corrupt cache file, etc.) so a
+/// cache problem degrades to a normal `pkgdb` call rather than failing the
+/// command.
+fn read_cached_search_results(flox: &Flox, cache_key: &str) -> Option<SearchResults> {
+    let cache_file = flox.cache_dir.join(SEARCH_CACHE_FILE_NAME);
+    let contents = fs::read_to_string(cache_file).ok()?;
+    let cache: HashMap<String, SearchResults> = serde_json::from_str(&contents).ok()?;
+    cache.get(cache_key).cloned()
+}
+
+/// Persist `results` under `cache_key`, merging into whatever's already
+/// cached. Entries for other cache keys (i.e. other resolution inputs) are
+/// left alone; since the key already encodes every input, a stale entry is
+/// simply never looked up again once those inputs change.
+fn write_cached_search_results(
+    flox: &Flox,
+    cache_key: &str,
+    results: &SearchResults,
+) -> Result<()> {
+    let cache_file = flox.cache_dir.join(SEARCH_CACHE_FILE_NAME);
+    let mut cache: HashMap<String, SearchResults> = fs::read_to_string(&cache_file)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default();
+    cache.insert(cache_key.to_string(), results.clone());
+    fs::create_dir_all(&flox.cache_dir)
+        .with_context(|| format!("couldn't create cache dir {:?}", flox.cache_dir))?;
+    fs::write(&cache_file, serde_json::to_string(&cache)?)
+        .with_context(|| format!("couldn't write search cache to {cache_file:?}"))
+}
+
 /// An intermediate representation of a search result used for rendering
 #[derive(Debug, PartialEq, Clone)]
 struct DisplayItem {
@@ -160,14 +383,20 @@ struct DisplayItem {
     description: Option<String>,
     /// Whether to join the `input` and `package` fields with a separator when rendering
     render_with_input: bool,
+    /// The systems this package is available on, only populated (and only
+    /// rendered) when `--all-systems` is given
+    systems: Vec<String>,
 }
 
 fn render_search_results_user_facing(
     search_term: &str,
     search_results: SearchResults,
+    search_params: &SearchParams,
+    all_systems: bool,
 ) -> Result<()> {
     // Nothing to display
     if search_results.results.is_empty() {
+        print_suggestions_for_empty_results(search_term, search_params);
         bail!("No packages matched this search term: {}", search_term);
     }
     // Search results contain a lot of information, but all we need for rendering are
@@ -181,9 +410,15 @@ fn render_search_results_user_facing(
                 package: r.rel_path.join("."),
                 description: r.description.map(|s| s.replace('\n', " ")),
                 render_with_input: false,
+                systems: vec![r.system],
             })
         })
         .collect::<Result<Vec<_>>>()?;
+    let display_items = if all_systems {
+        merge_systems_for_display(display_items)
+    } else {
+        display_items
+    };
 
     let deduped_display_items = dedup_and_disambiguate_display_items(display_items);
     if deduped_display_items.is_empty() {
@@ -206,19 +441,53 @@ fn render_search_results_user_facing(
     let mut writer = BufWriter::new(std::io::stdout());
     let default_desc = String::from(DEFAULT_DESCRIPTION);
     for d in deduped_display_items.into_iter() {
+        let systems = d.systems.join(", ");
         let package = if d.render_with_input {
             [d.input, d.package].join(SEARCH_INPUT_SEPARATOR)
         } else {
             d.package
         };
         let desc: String = d.description.unwrap_or(default_desc.clone());
-        writeln!(&mut writer, "{package:<column_width$}  {desc}")?;
+        if all_systems {
+            writeln!(&mut writer, "{package:<column_width$}  {desc}  ({systems})")?;
+        } else {
+            writeln!(&mut writer, "{package:<column_width$}  {desc}")?;
+        }
     }
     writer.flush().context("couldn't flush search results")?;
     eprintln!("\nUse `flox show {{package}}` to see available versions");
     Ok(())
 }
 
+/// Merge [DisplayItem]s that only differ by which system they were found on
+/// into a single entry per (input, package) pair, collecting the systems
+/// into that entry's `systems` field. Used for `--all-systems` rendering so
+/// a package isn't printed once per system.
+fn merge_systems_for_display(display_items: Vec<DisplayItem>) -> Vec<DisplayItem> {
+    let mut order: Vec<(String, String)> = Vec::new();
+    let mut merged: HashMap<(String, String), DisplayItem> = HashMap::new();
+    for item in display_items {
+        let key = (item.input.clone(), item.package.clone());
+        merged
+            .entry(key.clone())
+            .and_modify(|existing| {
+                for system in &item.systems {
+                    if !existing.systems.contains(system) {
+                        existing.systems.push(system.clone());
+                    }
+                }
+            })
+            .or_insert_with(|| {
+                order.push(key);
+                item
+            });
+    }
+    order
+        .into_iter()
+        .filter_map(|key| merged.remove(&key))
+        .collect()
+}
+
 fn render_search_results_json(search_results: SearchResults) -> Result<()> {
     let json = serde_json::to_string(&search_results.results)?;
     println!("{}", json);
@@ -280,12 +549,40 @@ fn dedup_and_disambiguate_display_items(mut display_items: Vec<DisplayItem>) ->
 /// Show detailed package information
 #[derive(Bpaf, Clone)]
 pub struct Show {
+    /// print show output as JSON
+    #[bpaf(long)]
+    pub json: bool,
+
     /// Whether to show all available package versions
     #[bpaf(long)]
     pub all: bool,
 
+    /// never touch the network; only reuse a cached result for an
+    /// identical query, and error out if none exists instead of invoking
+    /// `pkgdb`
+    #[bpaf(long)]
+    pub offline: bool,
+
+    /// restrict results to a particular Nix system, e.g. `x86_64-linux`.
+    /// May be given multiple times; defaults to the host system.
+    #[bpaf(long("system"), argument("SYSTEM"))]
+    pub systems: Vec<String>,
+
+    /// show versions for all supported systems instead of just the host
+    /// system, grouping them by system
+    #[bpaf(long)]
+    pub all_systems: bool,
+
+    /// treat a bare version after `@` as an exact pin instead of the
+    /// default `^` (caret) range, e.g. `node@18.1.0` matches only `18.1.0`
+    /// rather than `>=18.1.0 <19.0.0`
+    #[bpaf(long)]
+    pub exact_version: bool,
+
     /// The package to show detailed information about. Must be an exact match
     /// for a package name e.g. something copy-pasted from the output of `flox search`.
+    /// If both the regex and the range fail to parse, the regex error is
+    /// reported first, since it's validated before the range is.
     #[bpaf(positional("search-term"))]
     pub search_term: String,
 }
@@ -328,68 +625,125 @@ impl Show {
                     (manifest, None)
                 },
             };
+        let global_manifest = global_manifest_path(&flox).try_into()?;
+        let normalized_term =
+            normalize_semver_range(&show_package_name(&self.search_term)?, self.exact_version);
+        let cache_key = search_cache_key(
+            &normalized_term,
+            &manifest,
+            &global_manifest,
+            lockfile.as_ref(),
+            &self.systems,
+            self.all_systems,
+            self.exact_version,
+            Features::parse()?.search_strategy,
+        )?;
+
         let search_params = construct_show_params(
             &self.search_term,
             manifest,
-            global_manifest_path(&flox).try_into()?,
+            global_manifest,
             lockfile,
+            &self.systems,
+            self.all_systems,
+            self.exact_version,
         )?;
 
-        let (search_results, exit_status) = do_search(&search_params)?;
+        if self.offline {
+            debug!("running offline; reusing a cached result or refusing to query pkgdb");
+        }
+        let cached = read_cached_search_results(&flox, &cache_key);
+        let (search_results, exit_status) = match cached {
+            Some(search_results) => {
+                debug!("using cached show results for key {cache_key}");
+                (search_results, None)
+            },
+            None if self.offline => {
+                bail!(
+                    "--offline was given but no cached result exists for this query; run \
+                     the show once without --offline to populate the cache"
+                );
+            },
+            None => {
+                let (search_results, exit_status) = do_search(&search_params)?;
+                write_cached_search_results(&flox, &cache_key, &search_results)?;
+                (search_results, Some(exit_status))
+            },
+        };
 
-        if search_results.results.is_empty() {
+        // As with `flox search --json`, a `--json` miss is just an empty
+        // array with a successful exit, not a human-facing bail; only the
+        // non-JSON path gets the "did you mean" suggestions and a hard error.
+        if search_results.results.is_empty() && !self.json {
+            let package_name = show_package_name(&self.search_term)?;
+            print_suggestions_for_empty_results(&package_name, &search_params);
             bail!("no packages matched this search term: {}", self.search_term);
         }
         // Render what we have no matter what, then indicate whether we encountered an error.
         // FIXME: We may have warnings on `stderr` even with a successful call to `pkgdb`.
         //        We aren't checking that at all at the moment because better overall error handling
         //        is coming in a later PR.
-        render_show(search_results.results.as_slice(), self.all)?;
-        if exit_status.success() {
-            Ok(())
+        if self.json {
+            render_show_json(search_results.results.as_slice(), self.all)?;
         } else {
-            bail!(
+            render_show(search_results.results.as_slice(), self.all, self.all_systems)?;
+        }
+        match exit_status {
+            Some(exit_status) if !exit_status.success() => bail!(
                 "pkgdb exited with status code: {}",
                 exit_status.code().unwrap_or(-1),
-            );
+            ),
+            _ => Ok(()),
         }
     }
 }
 
-fn construct_show_params(
-    search_term: &str,
-    manifest: PathOrJson,
-    global_manifest: PathOrJson,
-    lockfile: Option<PathOrJson>,
-) -> Result<SearchParams> {
+/// Split a `flox show` search term of the form `[<input>:]<package>` down
+/// to just its package-name part (which may still carry an `@<RANGE>`
+/// suffix), the same way `construct_show_params` parses it for `Query`.
+fn show_package_name(search_term: &str) -> Result<String> {
     let parts = search_term
         .split(SEARCH_INPUT_SEPARATOR)
         .map(String::from)
         .collect::<Vec<_>>();
-    let (_input_name, package_name) = match parts.as_slice() {
-        [package_name] => (None, Some(package_name.to_owned())),
-        [input_name, package_name] => (Some(input_name.to_owned()), Some(package_name.to_owned())),
+    match parts.as_slice() {
+        [package_name] => Ok(package_name.to_owned()),
+        [_input_name, package_name] => Ok(package_name.to_owned()),
         _ => Err(ShowError::InvalidSearchTerm(search_term.to_owned()))?,
-    };
+    }
+}
 
-    let query = Query::from_str(
-        package_name.as_ref().unwrap(), // We already know it's Some(_)
+fn construct_show_params(
+    search_term: &str,
+    manifest: PathOrJson,
+    global_manifest: PathOrJson,
+    lockfile: Option<PathOrJson>,
+    systems: &[String],
+    all_systems: bool,
+    exact_version: bool,
+) -> Result<SearchParams> {
+    let package_name = show_package_name(search_term)?;
+    let mut query = Query::from_str(
+        &normalize_semver_range(&package_name, exact_version),
         Features::parse()?.search_strategy == SearchStrategy::MatchName,
     )?;
+    query.systems = systems_for_query(systems, all_systems)?;
     let search_params = SearchParams {
         manifest,
         global_manifest,
         lockfile,
         query,
+        nix_config: default_nix_config(),
     };
     debug!("show params raw: {:?}", search_params);
     Ok(search_params)
 }
 
-fn render_show(search_results: &[SearchResult], all: bool) -> Result<()> {
+/// Collect every result belonging to the top (best-matching) package name
+/// from a set of search results, along with that package's name.
+fn group_top_package_results(search_results: &[SearchResult]) -> Result<(String, Vec<&SearchResult>)> {
     let mut pkg_name = None;
     let mut results = Vec::new();
-    // Collect all versions of the top search result
     for package in search_results.iter() {
         let this_pkg_name = package.rel_path.join(".");
         if pkg_name.is_none() {
@@ -404,45 +758,503 @@ fn render_show(search_results: &[SearchResult], all: bool) -> Result<()> {
         // set of results is non-empty.
         bail!("no packages found");
     }
-    let pkg_name = pkg_name.unwrap();
+    Ok((pkg_name.unwrap(), results))
+}
+
+fn render_show(search_results: &[SearchResult], all: bool, all_systems: bool) -> Result<()> {
+    let (pkg_name, results) = group_top_package_results(search_results)?;
     let description = results[0]
         .description
         .as_ref()
         .map(|d| d.replace('\n', " "))
         .unwrap_or(DEFAULT_DESCRIPTION.into());
-    let versions = if all {
-        let multiple_versions = results
-            .iter()
-            .filter_map(|sr| {
-                // Don't show a "latest" search result, it's just
-                // a duplicate
-                if sr.subtree == Subtree::Catalog
-                    && sr
-                        .abs_path
-                        .last()
-                        .map(|version| version == "latest")
-                        .unwrap_or(false)
-                {
-                    return None;
-                }
-                let name = sr.rel_path.join(".");
-                // We don't print packages that don't have a version since
-                // the resolver will always rank versioned packages higher.
-                sr.version.clone().map(|version| [name, version].join("@"))
-            })
-            .collect::<Vec<_>>();
-        multiple_versions.join(", ")
-    } else {
-        let sr = results[0];
-        let name = sr.rel_path.join(".");
-        let version = sr.version.clone();
-        if let Some(version) = version {
-            [name, version].join("@")
-        } else {
-            name
-        }
-    };
     println!("{pkg_name} - {description}");
-    println!("    {pkg_name} - {versions}");
+    print_metadata_block(results[0]);
+
+    if all_systems {
+        // Group versions by system so a mixed-fleet manifest author can see
+        // at a glance where each version is available.
+        let mut versions_by_system: HashMap<String, Vec<String>> = HashMap::new();
+        let mut systems_in_order = Vec::new();
+        for sr in version_entries(&results, all) {
+            let entry = versions_by_system
+                .entry(sr.system.clone())
+                .or_insert_with(|| {
+                    systems_in_order.push(sr.system.clone());
+                    Vec::new()
+                });
+            entry.push(version_display_name(sr));
+        }
+        for system in systems_in_order {
+            let versions = versions_by_system
+                .remove(&system)
+                .unwrap_or_default()
+                .join(", ");
+            println!("    [{system}] {pkg_name} - {versions}");
+        }
+    } else {
+        let versions = version_entries(&results, all)
+            .map(version_display_name)
+            .collect::<Vec<_>>()
+            .join(", ");
+        println!("    {pkg_name} - {versions}");
+    }
+    Ok(())
+}
+
+/// Print an aligned key/value block of whatever package metadata pkgdb
+/// reported, similar to `cargo info`'s package summary. Fields that pkgdb
+/// didn't report (e.g. no license data for this package) are omitted rather
+/// than printed as blank.
+fn print_metadata_block(sr: &SearchResult) {
+    let mut rows: Vec<(&str, String)> = Vec::new();
+    if let Some(license) = &sr.license {
+        rows.push(("license", license.clone()));
+    }
+    if let Some(homepage) = &sr.homepage {
+        rows.push(("homepage", homepage.clone()));
+    }
+    if !sr.maintainers.is_empty() {
+        rows.push(("maintainers", sr.maintainers.join(", ")));
+    }
+    if sr.broken {
+        rows.push(("broken", "yes".to_string()));
+    }
+    if sr.unfree {
+        rows.push(("unfree", "yes".to_string()));
+    }
+    if sr.insecure {
+        rows.push(("insecure", "yes".to_string()));
+    }
+    if rows.is_empty() {
+        return;
+    }
+    let key_width = rows.iter().map(|(key, _)| key.len()).max().unwrap_or(0);
+    for (key, value) in rows {
+        println!("    {key:<key_width$}  {value}");
+    }
+}
+
+/// A single version's worth of `flox show --json` output: one object per
+/// matching version, carrying the same metadata [print_metadata_block]
+/// shows in the human-readable view.
+#[derive(Debug, serde::Serialize)]
+struct ShowJsonEntry {
+    name: String,
+    version: Option<String>,
+    description: Option<String>,
+    license: Option<String>,
+    homepage: Option<String>,
+    maintainers: Vec<String>,
+    broken: bool,
+    unfree: bool,
+    insecure: bool,
+    system: String,
+}
+
+fn render_show_json(search_results: &[SearchResult], all: bool) -> Result<()> {
+    if search_results.is_empty() {
+        println!("[]");
+        return Ok(());
+    }
+    let (_, results) = group_top_package_results(search_results)?;
+    let entries = version_entries(&results, all)
+        .map(|sr| ShowJsonEntry {
+            name: sr.rel_path.join("."),
+            version: sr.version.clone(),
+            description: sr.description.clone().map(|d| d.replace('\n', " ")),
+            license: sr.license.clone(),
+            homepage: sr.homepage.clone(),
+            maintainers: sr.maintainers.clone(),
+            broken: sr.broken,
+            unfree: sr.unfree,
+            insecure: sr.insecure,
+            system: sr.system.clone(),
+        })
+        .collect::<Vec<_>>();
+    println!("{}", serde_json::to_string(&entries)?);
     Ok(())
-}
\ No newline at end of file
+}
+
+/// The search results to render as "versions", depending on `--all`:
+/// either every matching version, or just the best (first) match.
+fn version_entries<'a>(
+    results: &'a [&'a SearchResult],
+    all: bool,
+) -> Box<dyn Iterator<Item = &'a SearchResult> + 'a> {
+    if all {
+        Box::new(results.iter().copied().filter(|sr| {
+            // Don't show a "latest" search result, it's just a duplicate
+            let is_latest_duplicate = sr.subtree == Subtree::Catalog
+                && sr
+                    .abs_path
+                    .last()
+                    .map(|version| version == "latest")
+                    .unwrap_or(false);
+            // We don't print packages that don't have a version since the
+            // resolver will always rank versioned packages higher.
+            !is_latest_duplicate && sr.version.is_some()
+        }))
+    } else {
+        Box::new(results.first().copied().into_iter())
+    }
+}
+
+/// Render a single search result as `name@version`, or just `name` if it
+/// has no version.
+fn version_display_name(sr: &SearchResult) -> String {
+    let name = sr.rel_path.join(".");
+    match sr.version.clone() {
+        Some(version) => [name, version].join("@"),
+        None => name,
+    }
+}
+
+/// When a search or show query comes back empty, run a broadened query to
+/// gather a pool of candidate package names and print up to
+/// [MAX_SUGGESTIONS] "did you mean ...?" lines on stderr, ranked by edit
+/// distance to the original term. This mirrors how `cargo` uses
+/// `lev_distance` to hint at unknown commands.
+///
+/// Best-effort: any failure to run the broadened query is swallowed so that
+/// we don't mask the original "no results" error with an unrelated one.
+fn print_suggestions_for_empty_results(search_term: &str, search_params: &SearchParams) {
+    let term = bare_search_term(search_term);
+    let Some(candidates) = broadened_candidate_names(term, search_params) else {
+        return;
+    };
+
+    let max_distance = std::cmp::max(1, term.len() / 3);
+    let mut suggestions = candidates
+        .into_iter()
+        .filter_map(|candidate| {
+            let distance = levenshtein_distance(term, &candidate);
+            (distance <= max_distance).then_some((distance, candidate))
+        })
+        .collect::<Vec<_>>();
+    suggestions.sort_by(|(a_dist, a_name), (b_dist, b_name)| {
+        a_dist.cmp(b_dist).then_with(|| a_name.cmp(b_name))
+    });
+    suggestions.truncate(MAX_SUGGESTIONS);
+
+    if suggestions.is_empty() {
+        return;
+    }
+    eprintln!("\nDid you mean one of these?");
+    for (_, name) in suggestions {
+        eprintln!("    {name}");
+    }
+}
+
+/// Strip the `@<SEMVER-RANGE>` suffix (if any) from a search term, leaving
+/// just the part we match package names against.
+fn bare_search_term(search_term: &str) -> &str {
+    search_term
+        .rsplit_once('@')
+        .map_or(search_term, |(term, _range)| term)
+}
+
+/// Prefixes of `term` to try broadening over, longest first down to a
+/// single character. A single fixed-length prefix isn't enough: a typo near
+/// the front of the term (`pyhton` for `python`) means the term itself, and
+/// even its first few characters, never appear in the real package name at
+/// all, only a shorter shared prefix (`py`) does.
+fn candidate_prefixes(term: &str) -> impl Iterator<Item = String> + '_ {
+    let char_count = term.chars().count();
+    (1..=char_count)
+        .rev()
+        .map(move |len| term.chars().take(len).collect())
+}
+
+/// Try [candidate_prefixes] of `term` against `pkgdb` in turn, returning the
+/// first non-empty, deduplicated, sorted pool of candidate names. Returns
+/// `None` if every prefix, down to a single character, still comes back
+/// empty or errors out.
+fn broadened_candidate_names(term: &str, search_params: &SearchParams) -> Option<Vec<String>> {
+    for prefix in candidate_prefixes(term) {
+        let Ok(broadened_params) = broadened_search_params(&prefix, search_params) else {
+            continue;
+        };
+        let Ok((candidate_results, _)) = do_search(&broadened_params) else {
+            continue;
+        };
+        if candidate_results.results.is_empty() {
+            continue;
+        }
+        let mut candidates = candidate_results
+            .results
+            .into_iter()
+            .map(|r| r.rel_path.join("."))
+            .collect::<Vec<_>>();
+        candidates.sort();
+        candidates.dedup();
+        return Some(candidates);
+    }
+    None
+}
+
+/// Build a [SearchParams] whose query is just `prefix` (with no version
+/// range) instead of the original query, so the candidate pool isn't
+/// restricted to terms that literally contain the (possibly misspelled)
+/// search term. `prefix` is used as an unanchored regex, so it can match
+/// anywhere, not only at the start of a name.
+fn broadened_search_params(prefix: &str, search_params: &SearchParams) -> Result<SearchParams> {
+    let query = Query::from_str(prefix, false)?;
+    Ok(SearchParams {
+        manifest: search_params.manifest.clone(),
+        global_manifest: search_params.global_manifest.clone(),
+        lockfile: search_params.lockfile.clone(),
+        query,
+        nix_config: search_params.nix_config.clone(),
+    })
+}
+
+/// Levenshtein edit distance between two attribute-path strings, used to
+/// rank "did you mean ...?" candidates.
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+
+    let mut prev = (0..=b.len()).collect::<Vec<_>>();
+    let mut row = vec![0usize; b.len() + 1];
+
+    for (i, a_char) in a.iter().enumerate() {
+        row[0] = i + 1;
+        let mut prev_diag = prev[0];
+        for (j, b_char) in b.iter().enumerate() {
+            let cost = (a_char != b_char) as usize;
+            let new_diag = prev[j + 1];
+            row[j + 1] = std::cmp::min(std::cmp::min(row[j] + 1, prev[j + 1] + 1), prev_diag + cost);
+            prev_diag = new_diag;
+        }
+        std::mem::swap(&mut prev, &mut row);
+    }
+
+    prev[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn levenshtein_distance_identical() {
+        assert_eq!(levenshtein_distance("python", "python"), 0);
+    }
+
+    #[test]
+    fn levenshtein_distance_typo() {
+        // The motivating "did you mean" case: a single transposed-ish
+        // character a few positions in.
+        assert_eq!(levenshtein_distance("pyhton", "python"), 2);
+    }
+
+    #[test]
+    fn levenshtein_distance_unrelated() {
+        assert_eq!(levenshtein_distance("coreutils", ""), "coreutils".len());
+    }
+
+    #[test]
+    fn candidate_prefixes_pyhton_reaches_shared_prefix_with_python() {
+        // "pyhton" and "python" share only their first two characters, so
+        // the broadening loop must eventually try "py" even though none of
+        // the longer prefixes ("pyhton", "pyhto", "pyht", "pyh") are
+        // substrings of "python".
+        let prefixes = candidate_prefixes("pyhton").collect::<Vec<_>>();
+        assert_eq!(prefixes, vec!["pyhton", "pyhto", "pyht", "pyh", "py", "p"]);
+        assert!(prefixes.contains(&"py".to_string()));
+    }
+
+    #[test]
+    fn pyhton_python_distance_is_within_suggestion_threshold() {
+        let distance = levenshtein_distance("pyhton", "python");
+        let max_distance = std::cmp::max(1, "pyhton".len() / 3);
+        assert!(distance <= max_distance);
+    }
+
+    #[test]
+    fn normalize_semver_range_adds_caret_to_bare_version() {
+        assert_eq!(normalize_semver_range("node@18", false), "node@^18");
+    }
+
+    #[test]
+    fn normalize_semver_range_leaves_explicit_ranges_alone() {
+        assert_eq!(normalize_semver_range("node@^18", false), "node@^18");
+        assert_eq!(normalize_semver_range("node@>=16", false), "node@>=16");
+        assert_eq!(normalize_semver_range("node@~18.1", false), "node@~18.1");
+    }
+
+    #[test]
+    fn normalize_semver_range_respects_exact_version() {
+        assert_eq!(normalize_semver_range("node@18", true), "node@18");
+    }
+
+    #[test]
+    fn normalize_semver_range_without_at_is_unchanged() {
+        assert_eq!(normalize_semver_range("node", false), "node");
+    }
+
+    #[test]
+    fn systems_for_query_defaults_to_none() {
+        assert_eq!(systems_for_query(&[], false).unwrap(), None);
+    }
+
+    #[test]
+    fn systems_for_query_all_systems_is_empty_vec() {
+        assert_eq!(systems_for_query(&[], true).unwrap(), Some(Vec::new()));
+    }
+
+    #[test]
+    fn systems_for_query_explicit_systems() {
+        let systems = vec!["x86_64-linux".to_string()];
+        assert_eq!(
+            systems_for_query(&systems, false).unwrap(),
+            Some(systems.clone())
+        );
+    }
+
+    #[test]
+    fn systems_for_query_rejects_system_and_all_systems_together() {
+        let systems = vec!["x86_64-linux".to_string()];
+        assert!(systems_for_query(&systems, true).is_err());
+    }
+
+    #[test]
+    fn hash_path_or_json_is_stable_for_identical_json() {
+        let a = PathOrJson::Json(serde_json::json!({"version": 1}));
+        let b = PathOrJson::Json(serde_json::json!({"version": 1}));
+        assert_eq!(hash_path_or_json(&a).unwrap(), hash_path_or_json(&b).unwrap());
+    }
+
+    #[test]
+    fn hash_path_or_json_differs_for_different_json() {
+        let a = PathOrJson::Json(serde_json::json!({"version": 1}));
+        let b = PathOrJson::Json(serde_json::json!({"version": 2}));
+        assert_ne!(hash_path_or_json(&a).unwrap(), hash_path_or_json(&b).unwrap());
+    }
+
+    fn dummy_manifest() -> PathOrJson {
+        PathOrJson::Json(serde_json::json!({}))
+    }
+
+    #[test]
+    fn search_cache_key_is_stable_for_identical_inputs() {
+        let manifest = dummy_manifest();
+        let global_manifest = dummy_manifest();
+        let systems = vec!["x86_64-linux".to_string()];
+        let a = search_cache_key(
+            "node@^18",
+            &manifest,
+            &global_manifest,
+            None,
+            &systems,
+            false,
+            false,
+            SearchStrategy::MatchName,
+        )
+        .unwrap();
+        let b = search_cache_key(
+            "node@^18",
+            &manifest,
+            &global_manifest,
+            None,
+            &systems,
+            false,
+            false,
+            SearchStrategy::MatchName,
+        )
+        .unwrap();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn search_cache_key_differs_by_systems() {
+        let manifest = dummy_manifest();
+        let global_manifest = dummy_manifest();
+        let with_system = search_cache_key(
+            "python",
+            &manifest,
+            &global_manifest,
+            None,
+            &["x86_64-linux".to_string()],
+            false,
+            false,
+            SearchStrategy::MatchName,
+        )
+        .unwrap();
+        let all_systems = search_cache_key(
+            "python",
+            &manifest,
+            &global_manifest,
+            None,
+            &[],
+            true,
+            false,
+            SearchStrategy::MatchName,
+        )
+        .unwrap();
+        assert_ne!(with_system, all_systems);
+    }
+
+    #[test]
+    fn search_cache_key_differs_by_exact_version() {
+        let manifest = dummy_manifest();
+        let global_manifest = dummy_manifest();
+        let systems = vec![];
+        let caret = search_cache_key(
+            "node@^18",
+            &manifest,
+            &global_manifest,
+            None,
+            &systems,
+            false,
+            false,
+            SearchStrategy::MatchName,
+        )
+        .unwrap();
+        let exact = search_cache_key(
+            "node@18",
+            &manifest,
+            &global_manifest,
+            None,
+            &systems,
+            false,
+            true,
+            SearchStrategy::MatchName,
+        )
+        .unwrap();
+        assert_ne!(caret, exact);
+    }
+
+    fn display_item(name: &str, system: &str) -> DisplayItem {
+        DisplayItem {
+            input: "nixpkgs".to_string(),
+            package: name.to_string(),
+            description: None,
+            render_with_input: false,
+            systems: vec![system.to_string()],
+        }
+    }
+
+    #[test]
+    fn merge_systems_for_display_combines_same_package_across_systems() {
+        let merged = merge_systems_for_display(vec![
+            display_item("hello", "x86_64-linux"),
+            display_item("hello", "aarch64-darwin"),
+        ]);
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].systems, vec![
+            "x86_64-linux".to_string(),
+            "aarch64-darwin".to_string()
+        ]);
+    }
+
+    #[test]
+    fn merge_systems_for_display_keeps_different_packages_separate() {
+        let merged = merge_systems_for_display(vec![
+            display_item("hello", "x86_64-linux"),
+            display_item("coreutils", "x86_64-linux"),
+        ]);
+        assert_eq!(merged.len(), 2);
+    }
+}
@@ -51,12 +51,34 @@ pub enum FlagType<T> {
     /// a b c
     /// ```
     Custom(fn(&T) -> Vec<String>),
+    /// A Nix `--option <key> <value>` flag
+    ///
+    /// Renders one `--option` pair per `(key, value)` entry, following the
+    /// Nix CLI convention for passing arbitrary settings through on the
+    /// command line rather than via `nix.conf`/`NIX_CONFIG`.
+    ///
+    /// ```
+    /// --option key1 value1 --option key2 value2
+    /// ```
+    Option(fn(&T) -> Vec<(String, String)>),
+    /// A boolean flag that renders as `--foo` when true and `--no-foo` when
+    /// false, rather than being omitted when false like [FlagType::Bool].
+    ///
+    /// ```
+    /// --foo
+    /// --no-foo
+    /// ```
+    NegatableBool(fn(&T) -> bool),
 }
 
 impl<T: Deref<Target = bool>> FlagType<T> {
     pub const fn bool() -> FlagType<T> {
         FlagType::Bool(|s| *s.deref())
     }
+
+    pub const fn negatable_bool() -> FlagType<T> {
+        FlagType::NegatableBool(|s| *s.deref())
+    }
 }
 
 impl<T: Deref<Target = Vec<String>>> FlagType<T> {
@@ -102,6 +124,63 @@ where
                 }
             }
             FlagType::Custom(f) => f(self),
+            FlagType::Option(f) => {
+                let mut flags = Vec::new();
+                for (key, value) in f(self) {
+                    flags.push(Self::FLAG.to_string());
+                    flags.push(key);
+                    flags.push(value);
+                }
+                flags
+            }
+            FlagType::NegatableBool(f) => {
+                let name = Self::FLAG.trim_start_matches("--");
+                match f(self) {
+                    true => vec![format!("--{name}")],
+                    false => vec![format!("--no-{name}")],
+                }
+            }
         }
     }
+}
+
+/// Nix settings that would otherwise have to be forced through the ambient
+/// `NIX_CONFIG` environment variable (e.g. `allow-import-from-derivation`,
+/// which `pkgdb` disables by default but some flakes require). Carrying
+/// these as a [Flag] lets a call site set them per invocation instead of
+/// relying on process-wide environment state.
+///
+/// See https://cs.github.com/NixOS/nix/blob/499e99d099ec513478a2d3120b2af3a16d9ae49d/src/libutil/config.cc#L199
+/// for the settings Nix itself recognizes here.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NixConfigSettings {
+    /// `allow-import-from-derivation`
+    pub allow_import_from_derivation: Option<bool>,
+    /// `substituters`, a space separated list of binary cache URLs
+    pub substituters: Vec<String>,
+    /// `experimental-features`, e.g. `nix-command flakes`
+    pub experimental_features: Vec<String>,
+}
+
+impl Flag for NixConfigSettings {
+    const FLAG: &'static str = "--option";
+    const FLAG_TYPE: FlagType<Self> = FlagType::Option(|settings| {
+        let mut options = Vec::new();
+        if let Some(allow_ifd) = settings.allow_import_from_derivation {
+            options.push((
+                "allow-import-from-derivation".to_string(),
+                allow_ifd.to_string(),
+            ));
+        }
+        if !settings.substituters.is_empty() {
+            options.push(("substituters".to_string(), settings.substituters.join(" ")));
+        }
+        if !settings.experimental_features.is_empty() {
+            options.push((
+                "experimental-features".to_string(),
+                settings.experimental_features.join(" "),
+            ));
+        }
+        options
+    });
 }
\ No newline at end of file